@@ -13,6 +13,8 @@ use core::fmt;
 use core::ops::Deref;
 
 use embedded_hal::spi;
+#[cfg(feature = "async")]
+use embedded_hal_async::spi as spi_async;
 
 /// The command byte. This should be set as the first byte of the transfer to the DAC
 ///
@@ -60,11 +62,23 @@ struct DacState {
 }
 
 #[derive(Default)]
-struct DacConfig {
+/// The decoded contents of the `CONFIG` register, as returned by
+/// [`read_config`](Dac80501::read_config).
+pub struct DacConfig {
     ref_pwdwn: InternRefState,
     dac_pwdwn: PowerState,
 }
 impl DacConfig {
+    /// The internal reference state read back from the device.
+    pub fn internal_reference(&self) -> &InternRefState {
+        &self.ref_pwdwn
+    }
+
+    /// The DAC power state read back from the device.
+    pub fn power_state(&self) -> &PowerState {
+        &self.dac_pwdwn
+    }
+
     fn to_array(&self) -> [u8; 2] {
         [
             // When set to 1, this bit disables the device internal reference.
@@ -76,10 +90,23 @@ impl DacConfig {
     }
 }
 
-struct GainConfig {
+/// The decoded contents of the `GAIN` register, as returned by
+/// [`read_gain`](Dac80501::read_gain).
+pub struct GainConfig {
     ref_div: RefDivState,
     buff_gain: GainState,
 }
+impl GainConfig {
+    /// The reference divider state read back from the device.
+    pub fn reference_divider(&self) -> &RefDivState {
+        &self.ref_div
+    }
+
+    /// The output buffer gain read back from the device.
+    pub fn output_gain(&self) -> &GainState {
+        &self.buff_gain
+    }
+}
 impl Default for GainConfig {
     fn default() -> Self {
         Self {
@@ -152,6 +179,21 @@ impl Default for InternRefState {
     }
 }
 
+/// Whether a DACDATA write moves the output immediately or only stages it until an LDAC trigger.
+/// The device default is [`SyncMode::Async`].
+pub enum SyncMode {
+    /// The DAC_SYNC_EN bit is set: a DACDATA write only loads the register and the output is not
+    /// updated until [`trigger_update`](Dac80501::trigger_update) issues an LDAC.
+    Sync,
+    /// The DAC_SYNC_EN bit is cleared: a DACDATA write immediately updates the output.
+    Async,
+}
+impl Default for SyncMode {
+    fn default() -> Self {
+        Self::Async
+    }
+}
+
 #[derive(PartialEq, Eq)]
 /// The state of the DAC alarm  The device default is [`AlarmStatus::Low`]
 pub enum AlarmStatus {
@@ -162,6 +204,16 @@ pub enum AlarmStatus {
     Low,
 }
 
+/// The decoded contents of the `DEVID` register, useful as a cheap power-on self-test to confirm
+/// that the part instantiated in software matches the silicon on the bus.
+pub struct DeviceId {
+    /// The converter resolution in bits (16, 14 or 12) reported by the `RESOLUTION` field
+    /// (bits 14:12 of `DEVID`).
+    pub resolution: u8,
+    /// The die revision reported by the `VERSION` field (bits 3:0 of `DEVID`).
+    pub die_revision: u8,
+}
+
 #[derive(Debug)]
 /// The custom error for this crate
 pub enum DacError {
@@ -169,12 +221,15 @@ pub enum DacError {
     ValueOverflow,
     /// An internal embedded hal SPI transfer error
     SpiError,
+    /// The `DEVID` register reported a `RESOLUTION` code that does not match any known part
+    UnknownDevice,
 }
 impl fmt::Display for DacError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::ValueOverflow => f.write_str("The data value was too large for the selected DAC"),
             Self::SpiError => f.write_str("Internal HAL SPI error"),
+            Self::UnknownDevice => f.write_str("The device reported an unknown resolution code"),
         }
     }
 }
@@ -223,6 +278,66 @@ macro_rules! Dac {
                 Ok(())
             }
 
+            /// Sets the device output to the given target voltage, deriving the DAC code with
+            /// [`Self::code_for_voltage`] so callers can work in volts without re-deriving the
+            /// scaling whenever the gain or reference divider changes.
+            pub fn set_output_voltage(&mut self, volts: f32) -> Result<(), DacError> {
+                let level = self.code_for_voltage(volts)?;
+                self.data[0] = *Command::DACDATA;
+                self.data[1..].copy_from_slice(level.to_be_bytes().as_slice());
+                self.spi.write(&self.data).map_err(DacError::from)?;
+                Ok(())
+            }
+
+        }
+
+        impl<Spi> $Name<Spi> {
+            /// Converts a physical target voltage into the MSB-aligned straight-binary DAC code for
+            /// the current reference, divider and gain configuration. The code is `round(volts /
+            /// Vfs * 2^N)` for the device's N bits and is left-justified into the 16-bit data
+            /// field. Returns [`DacError::ValueOverflow`] if `volts` is negative or rounds to a
+            /// code of `2^N` or more (full scale or above).
+            pub fn code_for_voltage(&self, volts: f32) -> Result<u16, DacError> {
+                if volts < 0.0 {
+                    return Err(DacError::ValueOverflow);
+                }
+                let ratio = volts / self.full_scale_voltage();
+                let code = (ratio * (1u32 << $bits) as f32 + 0.5) as u32;
+                if code >= (1u32 << $bits) {
+                    return Err(DacError::ValueOverflow);
+                }
+                // Data are MSB aligned in straight binary format
+                Ok((code as u16) << (16 - $bits))
+            }
+        }
+
+        #[cfg(feature = "async")]
+        impl<Spi> $Name<Spi>
+        where
+            Spi: spi_async::SpiDevice,
+            Spi::Bus: spi_async::SpiBusWrite,
+            DacError: core::convert::From<<Spi as embedded_hal::spi::ErrorType>::Error>,
+        {
+            /// Async counterpart of [`Self::set_output_level`].
+            pub async fn set_output_level_async(&mut self, level: u16) -> Result<(), DacError> {
+                // Data are MSB aligned in straight binary format
+                if level as u32 & (1u32 << $bits) > 0 {
+                    return Err(DacError::ValueOverflow);
+                }
+                self.data[0] = *Command::DACDATA;
+                self.data[1..].copy_from_slice(level.to_be_bytes().as_slice());
+                self.spi.write(&self.data).await.map_err(DacError::from)?;
+                Ok(())
+            }
+
+            /// Async counterpart of [`Self::set_output_voltage`].
+            pub async fn set_output_voltage_async(&mut self, volts: f32) -> Result<(), DacError> {
+                let level = self.code_for_voltage(volts)?;
+                self.data[0] = *Command::DACDATA;
+                self.data[1..].copy_from_slice(level.to_be_bytes().as_slice());
+                self.spi.write(&self.data).await.map_err(DacError::from)?;
+                Ok(())
+            }
         }
 
         Dac!($(#[$meta])* $Name :! dc);
@@ -245,6 +360,61 @@ macro_rules! Dac {
                 self.spi.write(&self.data).map_err(DacError::from)?;
                 Ok(())
             }
+
+            /// Sets the device output to the given target voltage, deriving the DAC code with
+            /// [`Self::code_for_voltage`] so callers can work in volts without re-deriving the
+            /// scaling whenever the gain or reference divider changes.
+            pub fn set_output_voltage(&mut self, volts: f32) -> Result<(), DacError> {
+                let level = self.code_for_voltage(volts)?;
+                self.data[0] = *Command::DACDATA;
+                self.data[1..].copy_from_slice(level.to_be_bytes().as_slice());
+                self.spi.write(&self.data).map_err(DacError::from)?;
+                Ok(())
+            }
+        }
+
+        impl<Spi> $Name<Spi> {
+            /// Converts a physical target voltage into the straight-binary DAC code for the current
+            /// reference, divider and gain configuration. The code is `round(volts / Vfs * 2^16)`
+            /// for this 16-bit part. Returns [`DacError::ValueOverflow`] if `volts` is negative or
+            /// rounds to a code of `2^16` or more (full scale or above).
+            pub fn code_for_voltage(&self, volts: f32) -> Result<u16, DacError> {
+                if volts < 0.0 {
+                    return Err(DacError::ValueOverflow);
+                }
+                let ratio = volts / self.full_scale_voltage();
+                let code = (ratio * (1u32 << 16) as f32 + 0.5) as u32;
+                if code >= (1u32 << 16) {
+                    return Err(DacError::ValueOverflow);
+                }
+                Ok(code as u16)
+            }
+        }
+
+        #[cfg(feature = "async")]
+        impl<Spi> $Name<Spi>
+        where
+            Spi: spi_async::SpiDevice,
+            Spi::Bus: spi_async::SpiBusWrite,
+            DacError: core::convert::From<<Spi as embedded_hal::spi::ErrorType>::Error>,
+        {
+            /// Async counterpart of [`Self::set_output_level`].
+            pub async fn set_output_level_async(&mut self, level: u16) -> Result<(), DacError> {
+                // Data are MSB aligned in straight binary format
+                self.data[0] = *Command::DACDATA;
+                self.data[1..].copy_from_slice(level.to_be_bytes().as_slice());
+                self.spi.write(&self.data).await.map_err(DacError::from)?;
+                Ok(())
+            }
+
+            /// Async counterpart of [`Self::set_output_voltage`].
+            pub async fn set_output_voltage_async(&mut self, volts: f32) -> Result<(), DacError> {
+                let level = self.code_for_voltage(volts)?;
+                self.data[0] = *Command::DACDATA;
+                self.data[1..].copy_from_slice(level.to_be_bytes().as_slice());
+                self.spi.write(&self.data).await.map_err(DacError::from)?;
+                Ok(())
+            }
         }
 
         Dac!($(#[$meta])* $Name :! dc);
@@ -258,6 +428,23 @@ macro_rules! Dac {
             spi: Spi,
             data: [u8; 3],
             dac_state: DacState,
+            reference_voltage: f32,
+        }
+
+        impl<Spi> $Name<Spi> {
+            /// The full-scale output voltage for the current reference, divider and gain
+            /// configuration, computed as `reference_voltage * gain_factor / div_factor`.
+            fn full_scale_voltage(&self) -> f32 {
+                let gain_factor = match self.dac_state.gain.buff_gain {
+                    GainState::TwoX => 2.0,
+                    GainState::OneX => 1.0,
+                };
+                let div_factor = match self.dac_state.gain.ref_div {
+                    RefDivState::Half => 2.0,
+                    RefDivState::OneX => 1.0,
+                };
+                self.reference_voltage * gain_factor / div_factor
+            }
         }
 
         impl<Spi> $Name<Spi>
@@ -267,15 +454,27 @@ macro_rules! Dac {
             DacError: core::convert::From<<Spi as embedded_hal::spi::ErrorType>::Error>,
         {
             /// Creates a new instance of the specified dac with the internal state set to match
-            /// the device defaults
+            /// the device defaults. The reference voltage is set to 2.5 V to match the device
+            /// internal reference; use [`Self::with_reference_voltage`] when driving the part from
+            /// an external reference.
             pub fn new(spi: Spi) -> Self {
                 Self {
                     spi,
                     data: [0, 0, 0],
                     dac_state: DacState::default(),
+                    reference_voltage: 2.5,
                 }
             }
 
+            /// Sets the reference voltage used by [`Self::set_output_voltage`] and
+            /// [`Self::code_for_voltage`] to convert a physical target into a DAC code. This is the
+            /// voltage present at the reference before the internal divider and output gain are
+            /// applied. Defaults to 2.5 V (the internal reference).
+            pub fn with_reference_voltage(mut self, volts: f32) -> Self {
+                self.reference_voltage = volts;
+                self
+            }
+
 
             /// Enables and disables the device internal reference. The internal reference is on by default
             pub fn set_internal_reference(
@@ -329,6 +528,119 @@ macro_rules! Dac {
                 self.spi.write(&self.data).map_err(DacError::from)?;
                 Ok(())
             }
+
+            /// Issues a software reset by writing the soft-reset pattern (`0b1010`) to the
+            /// `SOFT-RESET` nibble of the `TRIGGER` register, returning the device to its power-on
+            /// defaults. The cached [`DacState`] is reset to `Default` to stay in sync with the
+            /// hardware.
+            pub fn reset(&mut self) -> Result<(), DacError> {
+                self.data[0] = *Command::TRIGGER;
+                self.data[1] = 0;
+                self.data[2] = 0b1010;
+                self.spi.write(&self.data).map_err(DacError::from)?;
+                self.dac_state = DacState::default();
+                Ok(())
+            }
+
+            /// Selects the output update mode by writing the `DAC_SYNC_EN` bit of the `SYNC`
+            /// register. The device itself handles the staging: once `DAC_SYNC_EN` is set a
+            /// subsequent [`Self::set_output_level`] or [`Self::set_output_voltage`] only loads the
+            /// `DACDATA` register and the staged value is not transferred to the output until
+            /// [`Self::trigger_update`] issues an LDAC. In [`SyncMode::Async`] (the default) a
+            /// DACDATA write updates the output immediately, so no separate trigger is needed.
+            pub fn set_sync_mode(&mut self, enable: SyncMode) -> Result<(), DacError> {
+                self.data[0] = *Command::SYNC;
+                self.data[1] = 0;
+                self.data[2] = matches!(enable, SyncMode::Sync) as u8;
+                self.spi.write(&self.data).map_err(DacError::from)?;
+                Ok(())
+            }
+
+            /// Transfers the value staged in `DACDATA` to the output by writing the `LDAC` bit of
+            /// the `TRIGGER` register. Only meaningful in [`SyncMode::Sync`]; in
+            /// [`SyncMode::Async`] the output already tracks the most recent write.
+            pub fn trigger_update(&mut self) -> Result<(), DacError> {
+                self.data[0] = *Command::TRIGGER;
+                self.data[1] = 0;
+                self.data[2] = 1 << 4;
+                self.spi.write(&self.data).map_err(DacError::from)?;
+                Ok(())
+            }
+        }
+
+        #[cfg(feature = "async")]
+        impl<Spi> $Name<Spi>
+        where
+            Spi: spi_async::SpiDevice,
+            Spi::Bus: spi_async::SpiBusWrite,
+            DacError: core::convert::From<<Spi as embedded_hal::spi::ErrorType>::Error>,
+        {
+            /// Async counterpart of [`Self::set_internal_reference`].
+            pub async fn set_internal_reference_async(
+                &mut self,
+                intern_ref: InternRefState,
+            ) -> Result<(), DacError> {
+                self.dac_state.config.ref_pwdwn = intern_ref;
+                self.data[0] = *Command::CONFIG;
+                self.data[1..].copy_from_slice(&self.dac_state.config.to_array());
+                self.spi.write(&self.data).await.map_err(DacError::from)?;
+                Ok(())
+            }
+
+            /// Async counterpart of [`Self::set_power_state`].
+            pub async fn set_power_state_async(&mut self, state: PowerState) -> Result<(), DacError> {
+                self.dac_state.config.dac_pwdwn = state;
+                self.data[0] = *Command::CONFIG;
+                self.data[1..].copy_from_slice(&self.dac_state.config.to_array());
+                self.spi.write(&self.data).await.map_err(DacError::from)?;
+                Ok(())
+            }
+
+            /// Async counterpart of [`Self::set_reference_divider`].
+            pub async fn set_reference_divider_async(&mut self, ref_div: RefDivState) -> Result<(), DacError> {
+                self.dac_state.gain.ref_div = ref_div;
+                self.data[0] = *Command::GAIN;
+                self.data[1..].copy_from_slice(&self.dac_state.gain.to_array());
+                self.spi.write(&self.data).await.map_err(DacError::from)?;
+                Ok(())
+            }
+
+            /// Async counterpart of [`Self::set_output_gain`].
+            pub async fn set_output_gain_async(&mut self, gain: GainState) -> Result<(), DacError> {
+                self.dac_state.gain.buff_gain = gain;
+                self.data[0] = *Command::GAIN;
+                self.data[1..].copy_from_slice(&self.dac_state.gain.to_array());
+                self.spi.write(&self.data).await.map_err(DacError::from)?;
+                Ok(())
+            }
+
+            /// Async counterpart of [`Self::reset`].
+            pub async fn reset_async(&mut self) -> Result<(), DacError> {
+                self.data[0] = *Command::TRIGGER;
+                self.data[1] = 0;
+                self.data[2] = 0b1010;
+                self.spi.write(&self.data).await.map_err(DacError::from)?;
+                self.dac_state = DacState::default();
+                Ok(())
+            }
+
+            /// Async counterpart of [`Self::set_sync_mode`].
+            pub async fn set_sync_mode_async(&mut self, enable: SyncMode) -> Result<(), DacError> {
+                self.data[0] = *Command::SYNC;
+                self.data[1] = 0;
+                self.data[2] = matches!(enable, SyncMode::Sync) as u8;
+                self.spi.write(&self.data).await.map_err(DacError::from)?;
+                Ok(())
+            }
+
+            /// Async counterpart of [`Self::trigger_update`].
+            pub async fn trigger_update_async(&mut self) -> Result<(), DacError> {
+                self.data[0] = *Command::TRIGGER;
+                self.data[1] = 0;
+                self.data[2] = 1 << 4;
+                self.spi.write(&self.data).await.map_err(DacError::from)?;
+                Ok(())
+            }
         }
 
         impl<Spi> $Name<Spi>
@@ -353,6 +665,190 @@ macro_rules! Dac {
                 }
             }
         }
+
+        impl<Spi> $Name<Spi>
+        where
+            Spi: spi::blocking::SpiDevice,
+            Spi::Bus: spi::blocking::SpiBusWrite + spi::blocking::SpiBusRead,
+            DacError: core::convert::From<<Spi as embedded_hal::spi::ErrorType>::Error>,
+        {
+            /// Performs a two-phase register read: the first frame sends the register address with
+            /// the read bit (DB23) set, the second frame clocks out the 16 data bits while sending
+            /// a NOOP. The register address therefore goes out on the bus before the data is read
+            /// back. See the DAC80501 datasheet section 7.5.1.1 "Register reads".
+            fn read_register(&mut self, register: u8) -> Result<u16, DacError> {
+                self.data[0] = register | 0x80;
+                self.data[1] = 0;
+                self.data[2] = 0;
+                self.spi.write(&self.data).map_err(DacError::from)?;
+                self.data[0] = *Command::NOOP;
+                self.data[1] = 0;
+                self.data[2] = 0;
+                self.spi.read(&mut self.data).map_err(DacError::from)?;
+                Ok(u16::from_be_bytes([self.data[1], self.data[2]]))
+            }
+
+            /// Reads the `DEVID` register (0x01) and decodes the `RESOLUTION` field (bits 14:12)
+            /// into the converter resolution in bits, letting callers verify that the instantiated
+            /// type matches the silicon on the bus. The `VERSION` field (bits 3:0) is returned as
+            /// the die revision. Per the DAC80501 datasheet the `RESOLUTION` field encodes `0b000`
+            /// as 16-bit, `0b001` as 14-bit and `0b010` as 12-bit; any other code yields
+            /// [`DacError::UnknownDevice`].
+            pub fn read_device_id(&mut self) -> Result<DeviceId, DacError> {
+                let word = self.read_register(*Command::DEVID)?;
+                let resolution = match (word >> 12) & 0b111 {
+                    0b000 => 16,
+                    0b001 => 14,
+                    0b010 => 12,
+                    _ => return Err(DacError::UnknownDevice),
+                };
+                Ok(DeviceId {
+                    resolution,
+                    die_revision: (word & 0x0f) as u8,
+                })
+            }
+
+            /// Reads back the `CONFIG` register (0x03) with a two-phase read and decodes it into a
+            /// [`DacConfig`], letting callers confirm the device accepted an internal-reference or
+            /// power-state change rather than trusting the write-only shadow state.
+            pub fn read_config(&mut self) -> Result<DacConfig, DacError> {
+                let word = self.read_register(*Command::CONFIG)?;
+                Ok(DacConfig {
+                    ref_pwdwn: if word & (1 << 8) != 0 {
+                        InternRefState::Disable
+                    } else {
+                        InternRefState::Enable
+                    },
+                    dac_pwdwn: if word & 1 != 0 {
+                        PowerState::Off
+                    } else {
+                        PowerState::On
+                    },
+                })
+            }
+
+            /// Reads back the `GAIN` register (0x04) with a two-phase read and decodes it into a
+            /// [`GainConfig`], letting callers confirm the reference divider and output gain the
+            /// device is actually using.
+            pub fn read_gain(&mut self) -> Result<GainConfig, DacError> {
+                let word = self.read_register(*Command::GAIN)?;
+                Ok(GainConfig {
+                    ref_div: if word & (1 << 8) != 0 {
+                        RefDivState::Half
+                    } else {
+                        RefDivState::OneX
+                    },
+                    buff_gain: if word & 1 != 0 {
+                        GainState::TwoX
+                    } else {
+                        GainState::OneX
+                    },
+                })
+            }
+
+            /// Reads back the MSB-aligned straight-binary word currently held in the `DACDATA`
+            /// register (0x08) with a two-phase read, to reconcile the driver with hardware after
+            /// an external reset or brown-out.
+            pub fn read_dac_data(&mut self) -> Result<u16, DacError> {
+                self.read_register(*Command::DACDATA)
+            }
+        }
+
+        #[cfg(feature = "async")]
+        impl<Spi> $Name<Spi>
+        where
+            Spi: spi_async::SpiDevice,
+            Spi::Bus: spi_async::SpiBusRead,
+            DacError: core::convert::From<<Spi as embedded_hal::spi::ErrorType>::Error>,
+        {
+            /// Async counterpart of [`Self::ref_alarm_status`].
+            pub async fn ref_alarm_status_async(&mut self) -> Result<AlarmStatus, DacError> {
+                self.data[0] = *Command::STATUS;
+                self.data[1] = 0;
+                self.data[2] = 0;
+                self.spi.read(&mut self.data).await.map_err(DacError::from)?;
+                if self.data[2] == 1 {
+                    Ok(AlarmStatus::High)
+                } else {
+                    Ok(AlarmStatus::Low)
+                }
+            }
+        }
+
+        #[cfg(feature = "async")]
+        impl<Spi> $Name<Spi>
+        where
+            Spi: spi_async::SpiDevice,
+            Spi::Bus: spi_async::SpiBusWrite + spi_async::SpiBusRead,
+            DacError: core::convert::From<<Spi as embedded_hal::spi::ErrorType>::Error>,
+        {
+            /// Async two-phase register read; counterpart of the blocking `read_register`.
+            async fn read_register_async(&mut self, register: u8) -> Result<u16, DacError> {
+                self.data[0] = register | 0x80;
+                self.data[1] = 0;
+                self.data[2] = 0;
+                self.spi.write(&self.data).await.map_err(DacError::from)?;
+                self.data[0] = *Command::NOOP;
+                self.data[1] = 0;
+                self.data[2] = 0;
+                self.spi.read(&mut self.data).await.map_err(DacError::from)?;
+                Ok(u16::from_be_bytes([self.data[1], self.data[2]]))
+            }
+
+            /// Async counterpart of [`Self::read_device_id`].
+            pub async fn read_device_id_async(&mut self) -> Result<DeviceId, DacError> {
+                let word = self.read_register_async(*Command::DEVID).await?;
+                let resolution = match (word >> 12) & 0b111 {
+                    0b000 => 16,
+                    0b001 => 14,
+                    0b010 => 12,
+                    _ => return Err(DacError::UnknownDevice),
+                };
+                Ok(DeviceId {
+                    resolution,
+                    die_revision: (word & 0x0f) as u8,
+                })
+            }
+
+            /// Async counterpart of [`Self::read_config`].
+            pub async fn read_config_async(&mut self) -> Result<DacConfig, DacError> {
+                let word = self.read_register_async(*Command::CONFIG).await?;
+                Ok(DacConfig {
+                    ref_pwdwn: if word & (1 << 8) != 0 {
+                        InternRefState::Disable
+                    } else {
+                        InternRefState::Enable
+                    },
+                    dac_pwdwn: if word & 1 != 0 {
+                        PowerState::Off
+                    } else {
+                        PowerState::On
+                    },
+                })
+            }
+
+            /// Async counterpart of [`Self::read_gain`].
+            pub async fn read_gain_async(&mut self) -> Result<GainConfig, DacError> {
+                let word = self.read_register_async(*Command::GAIN).await?;
+                Ok(GainConfig {
+                    ref_div: if word & (1 << 8) != 0 {
+                        RefDivState::Half
+                    } else {
+                        RefDivState::OneX
+                    },
+                    buff_gain: if word & 1 != 0 {
+                        GainState::TwoX
+                    } else {
+                        GainState::OneX
+                    },
+                })
+            }
+
+            /// Async counterpart of [`Self::read_dac_data`].
+            pub async fn read_dac_data_async(&mut self) -> Result<u16, DacError> {
+                self.read_register_async(*Command::DACDATA).await
+            }
+        }
     };
 }
 